@@ -1,8 +1,9 @@
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::Path;
 use streaming_iterator::StreamingIterator;
-use tree_sitter::{Language, Parser, Query, QueryCursor};
+use tree_sitter::{Parser, Query, QueryCursor};
 
-use crate::languages::{python, rust, typescript};
+use crate::languages::{self, LanguageSupport};
 
 enum SymbolType {
     Function,
@@ -14,43 +15,46 @@ enum SymbolType {
 pub struct Symbol {
     pub name: String,
     pub kind: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub start_byte: usize,
+    pub end_byte: usize,
 }
 
-pub fn detect_language(path: &Path) -> Option<Language> {
-    match path.extension()?.to_str()? {
-        "py" => Some(python::language()),
-        "ts" | "mts" | "cts" => Some(typescript::language_typescript()),
-        "tsx" => Some(typescript::language_tsx()),
-        "js" | "mjs" | "cjs" | "jsx" => Some(typescript::language_typescript()),
-        "rs" => Some(rust::language()),
-        _ => None,
-    }
+/// A symbol's source text plus the span it was extracted from, so callers
+/// (e.g. the `--json` output mode) can report line/byte ranges the way
+/// editor and LSP tooling expects.
+#[derive(Debug)]
+pub struct ExtractedSymbol {
+    pub code: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub start_byte: usize,
+    pub end_byte: usize,
 }
 
-fn lang_type(path: &Path) -> LangType {
-    match path.extension().and_then(|e| e.to_str()) {
-        Some("py") => LangType::Python,
-        Some("rs") => LangType::Rust,
-        _ => LangType::TypeScript,
+fn extracted(source: &str, range: std::ops::Range<usize>) -> ExtractedSymbol {
+    let start_line = source[..range.start].matches('\n').count() + 1;
+    let end_line = source[..range.end].matches('\n').count() + 1;
+    ExtractedSymbol {
+        code: source[range.clone()].to_string(),
+        start_line,
+        end_line,
+        start_byte: range.start,
+        end_byte: range.end,
     }
 }
 
-#[derive(PartialEq)]
-enum LangType {
-    Python,
-    Rust,
-    TypeScript,
-}
-
 pub fn extract_symbol(
     source: &str,
     path: &Path,
     symbol: &str,
-) -> Result<String, String> {
-    let lang = detect_language(path).ok_or("Unsupported file type")?;
+    with_docs: bool,
+) -> Result<ExtractedSymbol, String> {
+    let support = languages::detect_language(path).ok_or("Unsupported file type")?;
 
     let mut parser = Parser::new();
-    parser.set_language(&lang).map_err(|e| e.to_string())?;
+    parser.set_language(&support.language()).map_err(|e| e.to_string())?;
 
     let tree = parser.parse(source, None).ok_or("Failed to parse")?;
     let root = tree.root_node();
@@ -59,39 +63,154 @@ pub fn extract_symbol(
     if symbol.contains('.') {
         let parts: Vec<&str> = symbol.splitn(2, '.').collect();
         if parts.len() == 2 {
-            return extract_method(source, path, &lang, root, parts[0], parts[1]);
+            return extract_method(source, path, support.as_ref(), root, parts[0], parts[1], with_docs);
         }
     }
 
-    // try function first, then class, then interface (for TS)
-    if let Ok(result) = extract_by_type(source, path, &lang, root, symbol, SymbolType::Function) {
+    // try function first, then class, then interface
+    if let Ok(result) = extract_by_type(
+        source,
+        support.as_ref(),
+        root,
+        symbol,
+        SymbolType::Function,
+        with_docs,
+    ) {
         return Ok(result);
     }
-    if let Ok(result) = extract_by_type(source, path, &lang, root, symbol, SymbolType::Class) {
+    if let Ok(result) = extract_by_type(
+        source,
+        support.as_ref(),
+        root,
+        symbol,
+        SymbolType::Class,
+        with_docs,
+    ) {
         return Ok(result);
     }
-    if let Ok(result) = extract_by_type(source, path, &lang, root, symbol, SymbolType::Interface) {
+    if let Ok(result) = extract_by_type(
+        source,
+        support.as_ref(),
+        root,
+        symbol,
+        SymbolType::Interface,
+        with_docs,
+    ) {
         return Ok(result);
     }
 
-    Err(format!("Symbol not found: {symbol}"))
+    Err(not_found_with_suggestions("Symbol", symbol, source, path))
+}
+
+/// Walks `node` backwards over contiguous leading doc comments and
+/// attributes/decorators, returning the byte range extended to cover them.
+/// A predecessor is included only while it is a doc/attribute node for the
+/// file's language and separated from what follows it by nothing but
+/// whitespace with at most one blank line.
+fn extend_with_docs(
+    source: &str,
+    support: &dyn LanguageSupport,
+    node: tree_sitter::Node,
+) -> std::ops::Range<usize> {
+    let mut start = node.start_byte();
+    let mut current = node;
+
+    while let Some(prev) = current.prev_sibling() {
+        if !support.is_doc_node(prev.kind()) {
+            break;
+        }
+        let gap = &source[prev.end_byte()..start];
+        if !is_contiguous(gap) {
+            break;
+        }
+        start = prev.start_byte();
+        current = prev;
+    }
+
+    start..node.end_byte()
+}
+
+/// Whitespace-only gap containing at most one blank line (i.e. at most two
+/// newlines: one ending the doc node's line, one blank line before the next).
+fn is_contiguous(gap: &str) -> bool {
+    gap.chars().all(|c| c.is_whitespace()) && gap.matches('\n').count() <= 2
+}
+
+/// Builds a "Symbol not found" style error, appending up to three closest
+/// existing names (by Levenshtein edit distance) when any are close enough
+/// to plausibly be what the user meant.
+fn not_found_with_suggestions(what: &str, query: &str, source: &str, path: &Path) -> String {
+    let base = format!("{what} not found: {query}");
+
+    let candidates = match list_symbols(source, path) {
+        Ok(symbols) => symbols,
+        Err(_) => return base,
+    };
+
+    let suggestions = suggest_names(query, candidates.iter().map(|s| s.name.as_str()));
+    if suggestions.is_empty() {
+        return base;
+    }
+
+    format!("{base} — did you mean {}?", join_suggestions(&suggestions))
+}
+
+/// Ranks `names` by Levenshtein distance to `query`, keeping only those
+/// within a length-scaled threshold and returning up to three, closest first.
+fn suggest_names<'a>(query: &str, names: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let threshold = (query.chars().count() / 2).max(3);
+    let mut ranked: Vec<(usize, &str)> = names
+        .map(|name| (edit_distance(query, name), name))
+        .filter(|(dist, _)| *dist <= threshold)
+        .collect();
+    ranked.sort_by_key(|(dist, name)| (*dist, name.to_string()));
+    ranked.dedup_by(|a, b| a.1 == b.1);
+    ranked.into_iter().take(3).map(|(_, name)| name).collect()
+}
+
+fn join_suggestions(names: &[&str]) -> String {
+    names
+        .iter()
+        .map(|name| format!("'{name}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Classic two-row Levenshtein edit distance over Unicode scalar values,
+/// case-insensitive so casing differences rank as cheap as possible.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let n = b.len();
+
+    let mut prev: Vec<usize> = (0..=n).collect();
+    let mut curr = vec![0; n + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[n]
 }
 
 fn extract_method(
     source: &str,
     path: &Path,
-    lang: &Language,
+    support: &dyn LanguageSupport,
     root: tree_sitter::Node,
     class_name: &str,
     method_name: &str,
-) -> Result<String, String> {
-    let query_str = match lang_type(path) {
-        LangType::Python => python::method_query(class_name, method_name),
-        LangType::Rust => rust::method_query(class_name, method_name),
-        LangType::TypeScript => typescript::method_query(class_name, method_name),
-    };
+    with_docs: bool,
+) -> Result<ExtractedSymbol, String> {
+    let query_str = support.method_query(class_name, method_name);
 
-    let query = Query::new(lang, &query_str).map_err(|e| format!("Query error: {e}"))?;
+    let lang = support.language();
+    let query = Query::new(&lang, &query_str).map_err(|e| format!("Query error: {e}"))?;
     let mut cursor = QueryCursor::new();
     let mut matches = cursor.matches(&query, root, source.as_bytes());
 
@@ -100,103 +219,125 @@ fn extract_method(
             let name = query.capture_names()[cap.index as usize];
             if name == "method" {
                 let node = cap.node;
-                return Ok(source[node.byte_range()].to_string());
+                let range = if with_docs {
+                    extend_with_docs(source, support, node)
+                } else {
+                    node.byte_range()
+                };
+                return Ok(extracted(source, range));
             }
         }
     }
 
-    Err(format!("Method not found: {class_name}.{method_name}"))
+    let base = format!("Method not found: {class_name}.{method_name}");
+
+    let candidates = match list_symbols(source, path) {
+        Ok(symbols) => symbols,
+        Err(_) => return Err(base),
+    };
+
+    // Rank against the bare method name, not "Class.method" — the class
+    // qualifier would dominate the edit distance and hide real near-misses.
+    // Not every language's list_query tags methods with kind "method" (e.g.
+    // Python/Rust list them as "func", and TypeScript's list_query has no
+    // method pattern at all), so rank over all names rather than filtering
+    // by kind.
+    let names = candidates.iter().map(|s| s.name.as_str());
+    let suggestions = suggest_names(method_name, names);
+    if suggestions.is_empty() {
+        return Err(base);
+    }
+
+    Err(format!("{base} — did you mean {}?", join_suggestions(&suggestions)))
 }
 
 fn extract_by_type(
     source: &str,
-    path: &Path,
-    lang: &Language,
+    support: &dyn LanguageSupport,
     root: tree_sitter::Node,
     name: &str,
     sym_type: SymbolType,
-) -> Result<String, String> {
-    let query_str = match sym_type {
-        SymbolType::Function => match lang_type(path) {
-            LangType::Python => python::function_query(name),
-            LangType::Rust => rust::function_query(name),
-            LangType::TypeScript => typescript::function_query(name),
-        },
-        SymbolType::Class => match lang_type(path) {
-            LangType::Python => python::class_query(name),
-            LangType::Rust => rust::class_query(name),
-            LangType::TypeScript => typescript::class_query(name),
-        },
-        SymbolType::Interface => match lang_type(path) {
-            LangType::Python => return Err("Python has no interfaces".to_string()),
-            LangType::Rust => rust::trait_query(name),
-            LangType::TypeScript => typescript::interface_query(name),
-        },
+    with_docs: bool,
+) -> Result<ExtractedSymbol, String> {
+    let (query_str, capture_name) = match sym_type {
+        SymbolType::Function => (support.function_query(name), "function"),
+        SymbolType::Class => (support.class_query(name), "class"),
+        SymbolType::Interface => {
+            let query_str = support
+                .interface_query(name)
+                .ok_or_else(|| "This language has no interfaces".to_string())?;
+            (query_str, support.interface_capture_name())
+        }
     };
 
-    let query = Query::new(lang, &query_str).map_err(|e| format!("Query error: {e}"))?;
+    let lang = support.language();
+    let query = Query::new(&lang, &query_str).map_err(|e| format!("Query error: {e}"))?;
     let mut cursor = QueryCursor::new();
     let mut matches = cursor.matches(&query, root, source.as_bytes());
 
-    let capture_name = match sym_type {
-        SymbolType::Function => "function",
-        SymbolType::Class => "class",
-        SymbolType::Interface => {
-            if lang_type(path) == LangType::Rust {
-                "trait"
-            } else {
-                "interface"
-            }
-        }
-    };
-
     while let Some(m) = matches.next() {
         for cap in m.captures {
             let cname = query.capture_names()[cap.index as usize];
             if cname == capture_name {
                 let node = cap.node;
-                return Ok(source[node.byte_range()].to_string());
+                let range = if with_docs {
+                    extend_with_docs(source, support, node)
+                } else {
+                    node.byte_range()
+                };
+                return Ok(extracted(source, range));
             }
         }
     }
 
-    Err(format!("{} not found: {name}", capture_name))
+    Err(format!("{capture_name} not found: {name}"))
 }
 
 pub fn list_symbols(source: &str, path: &Path) -> Result<Vec<Symbol>, String> {
-    let lang = detect_language(path).ok_or("Unsupported file type")?;
+    let support = languages::detect_language(path).ok_or("Unsupported file type")?;
 
     let mut parser = Parser::new();
-    parser.set_language(&lang).map_err(|e| e.to_string())?;
+    parser.set_language(&support.language()).map_err(|e| e.to_string())?;
 
     let tree = parser.parse(source, None).ok_or("Failed to parse")?;
     let root = tree.root_node();
 
-    let query_str = match lang_type(path) {
-        LangType::Python => python::list_query(),
-        LangType::Rust => rust::list_query(),
-        LangType::TypeScript => typescript::list_query(),
-    };
-
+    let query_str = support.list_query();
+    let lang = support.language();
     let query = Query::new(&lang, query_str).map_err(|e| format!("Query error: {e}"))?;
     let mut cursor = QueryCursor::new();
     let mut matches = cursor.matches(&query, root, source.as_bytes());
 
     let mut symbols = Vec::new();
-    let mut seen = std::collections::HashSet::new();
+    let mut seen = HashSet::new();
 
     while let Some(m) = matches.next() {
+        let mut name_cap: Option<(&str, tree_sitter::Node)> = None;
+        let mut span_node: Option<tree_sitter::Node> = None;
+
         for cap in m.captures {
             let cname = query.capture_names()[cap.index as usize];
             if cname.ends_with("_name") {
-                let name = &source[cap.node.byte_range()];
-                let kind = cname.trim_end_matches("_name");
-                if seen.insert((name.to_string(), kind.to_string())) {
-                    symbols.push(Symbol {
-                        name: name.to_string(),
-                        kind: kind.to_string(),
-                    });
-                }
+                name_cap = Some((cname, cap.node));
+            } else {
+                span_node = Some(cap.node);
+            }
+        }
+
+        if let (Some((cname, name_node)), Some(span_node)) = (name_cap, span_node) {
+            let name = &source[name_node.byte_range()];
+            let kind = cname.trim_end_matches("_name");
+            if seen.insert((name.to_string(), kind.to_string())) {
+                let start = span_node.start_position();
+                let end = span_node.end_position();
+                symbols.push(Symbol {
+                    name: name.to_string(),
+                    kind: kind.to_string(),
+                    start_line: start.row + 1,
+                    end_line: end.row + 1,
+                    start_byte: span_node.start_byte(),
+                    end_byte: span_node.end_byte(),
+                });
             }
         }
     }
@@ -204,3 +345,214 @@ pub fn list_symbols(source: &str, path: &Path) -> Result<Vec<Symbol>, String> {
     Ok(symbols)
 }
 
+/// Finds the smallest named definition (function/method/class/etc.) whose
+/// span contains `line` (1-based), returning its qualified name (e.g.
+/// `Class.method`) and its source text.
+pub fn symbol_at_line(source: &str, path: &Path, line: usize) -> Result<(String, String), String> {
+    let support = languages::detect_language(path).ok_or("Unsupported file type")?;
+
+    let mut parser = Parser::new();
+    parser.set_language(&support.language()).map_err(|e| e.to_string())?;
+
+    let tree = parser.parse(source, None).ok_or("Failed to parse")?;
+    let root = tree.root_node();
+
+    let offset =
+        line_to_byte_offset(source, line).ok_or_else(|| format!("Line {line} is out of range"))?;
+    // Step past the line's indentation so a definition that starts on this
+    // line (e.g. an indented method) contains the offset, not just its parent.
+    let offset = offset
+        + source[offset..]
+            .bytes()
+            .take_while(|b| *b == b' ' || *b == b'\t')
+            .count();
+
+    let query_str = support.list_query();
+    let lang = support.language();
+    let query = Query::new(&lang, query_str).map_err(|e| format!("Query error: {e}"))?;
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(&query, root, source.as_bytes());
+
+    let mut best: Option<(tree_sitter::Node, String)> = None;
+
+    while let Some(m) = matches.next() {
+        let mut name_cap: Option<tree_sitter::Node> = None;
+        let mut span_node: Option<tree_sitter::Node> = None;
+
+        for cap in m.captures {
+            let cname = query.capture_names()[cap.index as usize];
+            if cname.ends_with("_name") {
+                name_cap = Some(cap.node);
+            } else {
+                span_node = Some(cap.node);
+            }
+        }
+
+        let (Some(name_node), Some(span_node)) = (name_cap, span_node) else {
+            continue;
+        };
+        if !span_node.byte_range().contains(&offset) {
+            continue;
+        }
+
+        let is_narrower = match &best {
+            None => true,
+            Some((current, _)) => {
+                span_node.byte_range().len() < current.byte_range().len()
+            }
+        };
+        if is_narrower {
+            best = Some((span_node, source[name_node.byte_range()].to_string()));
+        }
+    }
+
+    let (node, name) = best.ok_or_else(|| format!("No symbol found at line {line}"))?;
+
+    let qualified = match support.qualifier_for(node, source) {
+        Some(container) => format!("{container}.{name}"),
+        None => name,
+    };
+
+    Ok((qualified, source[node.byte_range()].to_string()))
+}
+
+/// Converts a 1-based line number to the byte offset of its first character.
+fn line_to_byte_offset(source: &str, line: usize) -> Option<usize> {
+    if line == 0 {
+        return None;
+    }
+    if line == 1 {
+        return Some(0);
+    }
+
+    let mut current_line = 1;
+    for (i, b) in source.bytes().enumerate() {
+        if b == b'\n' {
+            current_line += 1;
+            if current_line == line {
+                return Some(i + 1);
+            }
+        }
+    }
+    None
+}
+
+/// Extracts `symbol` plus the transitive closure of other definitions in the
+/// same file (any that the language's `list_query` matches, nested ones such
+/// as methods included) that it directly or indirectly references,
+/// in BFS discovery order (the seed first), each paired with its name.
+pub fn extract_closure(
+    source: &str,
+    path: &Path,
+    symbol: &str,
+    with_docs: bool,
+) -> Result<Vec<(String, ExtractedSymbol)>, String> {
+    let support = languages::detect_language(path).ok_or("Unsupported file type")?;
+
+    let mut parser = Parser::new();
+    parser.set_language(&support.language()).map_err(|e| e.to_string())?;
+
+    let tree = parser.parse(source, None).ok_or("Failed to parse")?;
+    let root = tree.root_node();
+
+    let definitions = index_definitions(source, support.as_ref(), root)?;
+
+    if !definitions.contains_key(symbol) {
+        return Err(not_found_with_suggestions("Symbol", symbol, source, path));
+    }
+
+    let reference_kinds = support.reference_kinds();
+
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut queue: VecDeque<String> = VecDeque::new();
+    let mut order: Vec<String> = Vec::new();
+
+    visited.insert(symbol.to_string());
+    queue.push_back(symbol.to_string());
+
+    while let Some(name) = queue.pop_front() {
+        let Some(&node) = definitions.get(&name) else {
+            continue;
+        };
+        order.push(name.clone());
+
+        let mut refs = Vec::new();
+        collect_identifiers(node, source, reference_kinds, &mut refs);
+
+        for reference in refs {
+            if reference != name
+                && definitions.contains_key(&reference)
+                && visited.insert(reference.clone())
+            {
+                queue.push_back(reference);
+            }
+        }
+    }
+
+    let pieces: Vec<(String, ExtractedSymbol)> = order
+        .into_iter()
+        .map(|name| {
+            let node = definitions[&name];
+            let range = if with_docs {
+                extend_with_docs(source, support.as_ref(), node)
+            } else {
+                node.byte_range()
+            };
+            (name, extracted(source, range))
+        })
+        .collect();
+
+    Ok(pieces)
+}
+
+/// Indexes the name and byte span of every definition the language's
+/// `list_query` matches, at any nesting depth, keeping the first match for a
+/// given name.
+fn index_definitions<'tree>(
+    source: &str,
+    support: &dyn LanguageSupport,
+    root: tree_sitter::Node<'tree>,
+) -> Result<HashMap<String, tree_sitter::Node<'tree>>, String> {
+    let query_str = support.list_query();
+    let lang = support.language();
+    let query = Query::new(&lang, query_str).map_err(|e| format!("Query error: {e}"))?;
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(&query, root, source.as_bytes());
+
+    let mut definitions = HashMap::new();
+
+    while let Some(m) = matches.next() {
+        let mut name_cap: Option<tree_sitter::Node> = None;
+        let mut span_node: Option<tree_sitter::Node> = None;
+
+        for cap in m.captures {
+            let cname = query.capture_names()[cap.index as usize];
+            if cname.ends_with("_name") {
+                name_cap = Some(cap.node);
+            } else {
+                span_node = Some(cap.node);
+            }
+        }
+
+        if let (Some(name_node), Some(span_node)) = (name_cap, span_node) {
+            let name = source[name_node.byte_range()].to_string();
+            definitions.entry(name).or_insert(span_node);
+        }
+    }
+
+    Ok(definitions)
+}
+
+/// Recursively collects the source text of every descendant node whose kind
+/// is in `kinds`, in source byte-position order (tree-sitter visits children
+/// left to right), so callers get a reproducible order instead of whatever a
+/// `HashSet`'s hash order happens to be on a given run.
+fn collect_identifiers(node: tree_sitter::Node, source: &str, kinds: &[&str], out: &mut Vec<String>) {
+    if kinds.contains(&node.kind()) {
+        out.push(source[node.byte_range()].to_string());
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_identifiers(child, source, kinds, out);
+    }
+}
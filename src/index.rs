@@ -0,0 +1,118 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::extract::{self, Symbol};
+use crate::languages;
+
+/// Directories that are never worth descending into for a source index.
+const SKIP_DIRS: &[&str] = &[".git", "target", "node_modules"];
+
+/// A file's symbols, as part of a project-wide index.
+pub struct FileSymbols {
+    pub path: PathBuf,
+    pub symbols: Vec<Symbol>,
+}
+
+/// A project-wide symbol match, returned by [`find_symbol`].
+pub struct Match {
+    pub path: PathBuf,
+    pub name: String,
+    pub kind: String,
+    pub start_line: usize,
+    pub distance: usize,
+}
+
+/// Recursively collects every file under `dir` whose extension is a
+/// supported language, skipping directories and entries it can't read rather
+/// than aborting the whole walk.
+fn walk_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+
+    while let Some(current) = dirs.pop() {
+        let entries = match fs::read_dir(&current) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let file_name = entry.file_name();
+            let name = file_name.to_string_lossy();
+
+            if path.is_dir() {
+                if !SKIP_DIRS.contains(&name.as_ref()) {
+                    dirs.push(path);
+                }
+            } else if languages::detect_language(&path).is_some() {
+                files.push(path);
+            }
+        }
+    }
+
+    files
+}
+
+/// Parses every supported file under `dir` in parallel and aggregates their
+/// symbols into a project-wide index. Files that fail to read or parse are
+/// skipped rather than failing the whole run.
+pub fn index_project(dir: &Path) -> Vec<FileSymbols> {
+    let mut files: Vec<FileSymbols> = walk_files(dir)
+        .into_par_iter()
+        .filter_map(|path| {
+            let source = fs::read_to_string(&path).ok()?;
+            let symbols = extract::list_symbols(&source, &path).ok()?;
+            Some(FileSymbols { path, symbols })
+        })
+        .collect();
+
+    // walk_files leaves file order dependent on the OS's directory listing
+    // order; sort so --list output (and anything built on top of it) is
+    // reproducible across runs.
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+/// Finds every project-wide symbol named `name`. When `fuzzy` is set,
+/// symbols within an edit-distance threshold (reusing the same ranker as
+/// the "did you mean" suggestions) are included too, ranked closest first.
+pub fn find_symbol(dir: &Path, name: &str, fuzzy: bool) -> Vec<Match> {
+    let threshold = (name.chars().count() / 2).max(3);
+
+    let mut matches: Vec<Match> = index_project(dir)
+        .into_iter()
+        .flat_map(|file| {
+            file.symbols.into_iter().filter_map(move |sym| {
+                if sym.name == name {
+                    return Some(Match {
+                        path: file.path.clone(),
+                        name: sym.name,
+                        kind: sym.kind,
+                        start_line: sym.start_line,
+                        distance: 0,
+                    });
+                }
+                if !fuzzy {
+                    return None;
+                }
+                let distance = extract::edit_distance(name, &sym.name);
+                if distance <= threshold {
+                    Some(Match {
+                        path: file.path.clone(),
+                        name: sym.name,
+                        kind: sym.kind,
+                        start_line: sym.start_line,
+                        distance,
+                    })
+                } else {
+                    None
+                }
+            })
+        })
+        .collect();
+
+    matches.sort_by_key(|m| (m.distance, m.path.clone(), m.start_line));
+    matches
+}
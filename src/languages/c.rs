@@ -0,0 +1,92 @@
+use tree_sitter::Language;
+
+use super::LanguageSupport;
+
+pub struct C;
+
+pub fn make() -> Box<dyn LanguageSupport> {
+    Box::new(C)
+}
+
+impl LanguageSupport for C {
+    fn language(&self) -> Language {
+        language()
+    }
+
+    fn function_query(&self, name: &str) -> String {
+        function_query(name)
+    }
+
+    fn class_query(&self, name: &str) -> String {
+        class_query(name)
+    }
+
+    fn method_query(&self, class_name: &str, method_name: &str) -> String {
+        method_query(class_name, method_name)
+    }
+
+    fn interface_query(&self, _name: &str) -> Option<String> {
+        None
+    }
+
+    fn list_query(&self) -> &'static str {
+        list_query()
+    }
+
+    fn is_doc_node(&self, kind: &str) -> bool {
+        is_doc_node(kind)
+    }
+
+    fn reference_kinds(&self) -> &'static [&'static str] {
+        &["identifier", "type_identifier"]
+    }
+}
+
+pub fn language() -> Language {
+    tree_sitter_c::LANGUAGE.into()
+}
+
+pub fn function_query(name: &str) -> String {
+    format!(
+        r#"(function_definition
+            declarator: (function_declarator
+                declarator: (identifier) @name
+            )
+            (#eq? @name "{name}")
+        ) @function"#
+    )
+}
+
+// C has structs, not classes.
+pub fn class_query(name: &str) -> String {
+    format!(
+        r#"(struct_specifier
+            name: (type_identifier) @name
+            (#eq? @name "{name}")
+        ) @class"#
+    )
+}
+
+// C has no methods; `Class.method` syntax falls back to a plain function
+// lookup by name, ignoring the class part.
+pub fn method_query(_class_name: &str, method_name: &str) -> String {
+    format!(
+        r#"(function_definition
+            declarator: (function_declarator
+                declarator: (identifier) @name
+            )
+            (#eq? @name "{method_name}")
+        ) @method"#
+    )
+}
+
+pub fn list_query() -> &'static str {
+    r#"
+    (function_definition declarator: (function_declarator declarator: (identifier) @func_name)) @function
+    (struct_specifier name: (type_identifier) @struct_name) @struct
+    "#
+}
+
+pub fn is_doc_node(kind: &str) -> bool {
+    matches!(kind, "comment")
+}
@@ -0,0 +1,133 @@
+use tree_sitter::Language;
+
+use super::LanguageSupport;
+
+pub struct Go;
+
+pub fn make() -> Box<dyn LanguageSupport> {
+    Box::new(Go)
+}
+
+impl LanguageSupport for Go {
+    fn language(&self) -> Language {
+        language()
+    }
+
+    fn function_query(&self, name: &str) -> String {
+        function_query(name)
+    }
+
+    fn class_query(&self, name: &str) -> String {
+        class_query(name)
+    }
+
+    fn method_query(&self, class_name: &str, method_name: &str) -> String {
+        method_query(class_name, method_name)
+    }
+
+    fn interface_query(&self, name: &str) -> Option<String> {
+        Some(interface_query(name))
+    }
+
+    fn list_query(&self) -> &'static str {
+        list_query()
+    }
+
+    fn is_doc_node(&self, kind: &str) -> bool {
+        is_doc_node(kind)
+    }
+
+    // Go methods aren't nested inside their type; the receiver lives on the
+    // method_declaration node itself, so there's no ancestor to walk.
+    fn qualifier_for(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        if node.kind() != "method_declaration" {
+            return None;
+        }
+        let receiver = node.child_by_field_name("receiver")?;
+        let mut cursor = receiver.walk();
+        for child in receiver.children(&mut cursor) {
+            if child.kind() == "parameter_declaration" {
+                let mut ty = child.child_by_field_name("type")?;
+                if ty.kind() == "pointer_type" {
+                    ty = ty.named_child(0)?;
+                }
+                return Some(source[ty.byte_range()].to_string());
+            }
+        }
+        None
+    }
+
+    fn reference_kinds(&self) -> &'static [&'static str] {
+        &["identifier", "type_identifier", "field_identifier"]
+    }
+}
+
+pub fn language() -> Language {
+    tree_sitter_go::LANGUAGE.into()
+}
+
+pub fn function_query(name: &str) -> String {
+    format!(
+        r#"(function_declaration
+            name: (identifier) @name
+            (#eq? @name "{name}")
+        ) @function"#
+    )
+}
+
+// Go has structs, not classes.
+pub fn class_query(name: &str) -> String {
+    format!(
+        r#"(type_declaration
+            (type_spec
+                name: (type_identifier) @name
+                type: (struct_type)
+            )
+            (#eq? @name "{name}")
+        ) @class"#
+    )
+}
+
+pub fn interface_query(name: &str) -> String {
+    format!(
+        r#"(type_declaration
+            (type_spec
+                name: (type_identifier) @name
+                type: (interface_type)
+            )
+            (#eq? @name "{name}")
+        ) @interface"#
+    )
+}
+
+// methods are plain functions with a receiver, so match on the receiver's type.
+pub fn method_query(type_name: &str, method_name: &str) -> String {
+    format!(
+        r#"(method_declaration
+            receiver: (parameter_list
+                (parameter_declaration
+                    type: [
+                        (type_identifier) @type_name
+                        (pointer_type (type_identifier) @type_name)
+                    ]
+                )
+            )
+            name: (field_identifier) @method_name
+            (#eq? @type_name "{type_name}")
+            (#eq? @method_name "{method_name}")
+        ) @method"#
+    )
+}
+
+pub fn list_query() -> &'static str {
+    r#"
+    (function_declaration name: (identifier) @func_name) @function
+    (method_declaration name: (field_identifier) @method_name) @method
+    (type_spec name: (type_identifier) @struct_name type: (struct_type)) @struct
+    (type_spec name: (type_identifier) @interface_name type: (interface_type)) @interface
+    "#
+}
+
+pub fn is_doc_node(kind: &str) -> bool {
+    matches!(kind, "comment")
+}
@@ -0,0 +1,116 @@
+use tree_sitter::Language;
+
+use super::LanguageSupport;
+
+pub struct Java;
+
+pub fn make() -> Box<dyn LanguageSupport> {
+    Box::new(Java)
+}
+
+impl LanguageSupport for Java {
+    fn language(&self) -> Language {
+        language()
+    }
+
+    fn function_query(&self, name: &str) -> String {
+        function_query(name)
+    }
+
+    fn class_query(&self, name: &str) -> String {
+        class_query(name)
+    }
+
+    fn method_query(&self, class_name: &str, method_name: &str) -> String {
+        method_query(class_name, method_name)
+    }
+
+    fn interface_query(&self, name: &str) -> Option<String> {
+        Some(interface_query(name))
+    }
+
+    fn list_query(&self) -> &'static str {
+        list_query()
+    }
+
+    fn is_doc_node(&self, kind: &str) -> bool {
+        is_doc_node(kind)
+    }
+
+    fn qualifier_for(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        let mut current = node;
+        while let Some(parent) = current.parent() {
+            if parent.kind() == "class_declaration" {
+                let name = parent.child_by_field_name("name")?;
+                return Some(source[name.byte_range()].to_string());
+            }
+            current = parent;
+        }
+        None
+    }
+
+    fn reference_kinds(&self) -> &'static [&'static str] {
+        &["identifier", "type_identifier"]
+    }
+}
+
+pub fn language() -> Language {
+    tree_sitter_java::LANGUAGE.into()
+}
+
+// Java has no top-level functions, so "function" lookup means "a method with
+// this name in any class" - useful when the caller doesn't know/care which
+// class it lives in.
+pub fn function_query(name: &str) -> String {
+    format!(
+        r#"(method_declaration
+            name: (identifier) @name
+            (#eq? @name "{name}")
+        ) @function"#
+    )
+}
+
+pub fn class_query(name: &str) -> String {
+    format!(
+        r#"(class_declaration
+            name: (identifier) @name
+            (#eq? @name "{name}")
+        ) @class"#
+    )
+}
+
+pub fn interface_query(name: &str) -> String {
+    format!(
+        r#"(interface_declaration
+            name: (identifier) @name
+            (#eq? @name "{name}")
+        ) @interface"#
+    )
+}
+
+pub fn method_query(class_name: &str, method_name: &str) -> String {
+    format!(
+        r#"(class_declaration
+            name: (identifier) @class_name
+            (#eq? @class_name "{class_name}")
+            body: (class_body
+                (method_declaration
+                    name: (identifier) @method_name
+                    (#eq? @method_name "{method_name}")
+                ) @method
+            )
+        )"#
+    )
+}
+
+pub fn list_query() -> &'static str {
+    r#"
+    (class_declaration name: (identifier) @class_name) @class
+    (interface_declaration name: (identifier) @interface_name) @interface
+    (method_declaration name: (identifier) @method_name) @method
+    "#
+}
+
+pub fn is_doc_node(kind: &str) -> bool {
+    matches!(kind, "line_comment" | "block_comment" | "marker_annotation" | "annotation")
+}
@@ -1,16 +1,77 @@
+pub mod c;
+pub mod go;
+pub mod java;
 pub mod python;
+pub mod rust;
 pub mod typescript;
 
-use tree_sitter::{Language, Query};
+use std::path::Path;
+use tree_sitter::Language;
 
+/// Everything `extract` needs to know about a language: how to build its
+/// tree-sitter grammar and how to query for functions, classes/structs,
+/// methods, interfaces and a project-wide listing, plus which node kinds
+/// count as leading docs/attributes. Implementing this trait (and adding an
+/// entry to `registry`) is all a new grammar needs to plug into `sread` —
+/// nothing in `extract.rs` has to change.
 pub trait LanguageSupport {
-    fn language() -> Language;
-    fn function_query() -> &'static str;
-    fn class_query() -> &'static str;
-    fn method_query() -> &'static str;
-    fn list_query() -> &'static str;
+    fn language(&self) -> Language;
+    fn function_query(&self, name: &str) -> String;
+    fn class_query(&self, name: &str) -> String;
+    fn method_query(&self, class_name: &str, method_name: &str) -> String;
+    /// `None` if the language has no interface-like construct (e.g. Python).
+    fn interface_query(&self, name: &str) -> Option<String>;
+    /// Capture name the interface query binds its match to (`"trait"` for Rust).
+    fn interface_capture_name(&self) -> &'static str {
+        "interface"
+    }
+    fn list_query(&self) -> &'static str;
+    fn is_doc_node(&self, kind: &str) -> bool;
+
+    /// For a definition node, the name of its enclosing container (e.g. the
+    /// type an `impl` block is for, or the class a method is nested in),
+    /// used to build a qualified `Class.method` path for reverse lookup.
+    /// `None` if the node has no such container.
+    fn qualifier_for(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        let _ = (node, source);
+        None
+    }
+
+    /// Node kinds that represent a reference to another symbol (identifiers
+    /// used as call targets, type names, etc), used to build the transitive
+    /// call-graph closure for `--expand`.
+    fn reference_kinds(&self) -> &'static [&'static str] {
+        &["identifier"]
+    }
+}
+
+/// Extensions mapped to the constructor for their `LanguageSupport` impl.
+type Registry = Vec<(&'static [&'static str], fn() -> Box<dyn LanguageSupport>)>;
+
+/// Adding a grammar means adding one entry here and nowhere else.
+fn registry() -> Registry {
+    vec![
+        (&["py"], python::make as fn() -> Box<dyn LanguageSupport>),
+        (&["rs"], rust::make),
+        (&["ts", "mts", "cts", "js", "mjs", "cjs", "jsx"], typescript::make_ts),
+        (&["tsx"], typescript::make_tsx),
+        (&["go"], go::make),
+        (&["java"], java::make),
+        (&["c", "h"], c::make),
+    ]
+}
+
+pub fn detect_language(path: &Path) -> Option<Box<dyn LanguageSupport>> {
+    let ext = path.extension()?.to_str()?;
+    registry()
+        .into_iter()
+        .find(|(exts, _)| exts.contains(&ext))
+        .map(|(_, make)| make())
 }
 
-pub fn get_query(lang: Language, query_str: &str) -> Result<Query, tree_sitter::QueryError> {
-    Query::new(&lang, query_str)
+/// Every extension with a registered `LanguageSupport`, so callers that need
+/// to recognize a `<file>.<ext>:<symbol>` split (the CLI's extract mode)
+/// can't drift out of sync with `detect_language`.
+pub fn supported_extensions() -> Vec<&'static str> {
+    registry().into_iter().flat_map(|(exts, _)| exts.iter().copied()).collect()
 }
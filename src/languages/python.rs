@@ -1,5 +1,55 @@
 use tree_sitter::Language;
 
+use super::LanguageSupport;
+
+pub struct Python;
+
+pub fn make() -> Box<dyn LanguageSupport> {
+    Box::new(Python)
+}
+
+impl LanguageSupport for Python {
+    fn language(&self) -> Language {
+        language()
+    }
+
+    fn function_query(&self, name: &str) -> String {
+        function_query(name)
+    }
+
+    fn class_query(&self, name: &str) -> String {
+        class_query(name)
+    }
+
+    fn method_query(&self, class_name: &str, method_name: &str) -> String {
+        method_query(class_name, method_name)
+    }
+
+    fn interface_query(&self, _name: &str) -> Option<String> {
+        None
+    }
+
+    fn list_query(&self) -> &'static str {
+        list_query()
+    }
+
+    fn is_doc_node(&self, kind: &str) -> bool {
+        is_doc_node(kind)
+    }
+
+    fn qualifier_for(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        let mut current = node;
+        while let Some(parent) = current.parent() {
+            if parent.kind() == "class_definition" {
+                let name = parent.child_by_field_name("name")?;
+                return Some(source[name.byte_range()].to_string());
+            }
+            current = parent;
+        }
+        None
+    }
+}
+
 pub fn language() -> Language {
     tree_sitter_python::LANGUAGE.into()
 }
@@ -37,6 +87,12 @@ pub fn method_query(class_name: &str, method_name: &str) -> String {
     )
 }
 
+/// Node kinds that count as leading docs/attributes for `--with-docs`:
+/// comments and decorators (`@staticmethod`, etc).
+pub fn is_doc_node(kind: &str) -> bool {
+    matches!(kind, "comment" | "decorator")
+}
+
 pub fn list_query() -> &'static str {
     r#"
     (function_definition name: (identifier) @func_name) @function
@@ -1,5 +1,63 @@
 use tree_sitter::Language;
 
+use super::LanguageSupport;
+
+pub struct Rust;
+
+pub fn make() -> Box<dyn LanguageSupport> {
+    Box::new(Rust)
+}
+
+impl LanguageSupport for Rust {
+    fn language(&self) -> Language {
+        language()
+    }
+
+    fn function_query(&self, name: &str) -> String {
+        function_query(name)
+    }
+
+    fn class_query(&self, name: &str) -> String {
+        class_query(name)
+    }
+
+    fn method_query(&self, class_name: &str, method_name: &str) -> String {
+        method_query(class_name, method_name)
+    }
+
+    fn interface_query(&self, name: &str) -> Option<String> {
+        Some(trait_query(name))
+    }
+
+    fn interface_capture_name(&self) -> &'static str {
+        "trait"
+    }
+
+    fn list_query(&self) -> &'static str {
+        list_query()
+    }
+
+    fn is_doc_node(&self, kind: &str) -> bool {
+        is_doc_node(kind)
+    }
+
+    fn qualifier_for(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        let mut current = node;
+        while let Some(parent) = current.parent() {
+            if parent.kind() == "impl_item" {
+                let ty = parent.child_by_field_name("type")?;
+                return Some(source[ty.byte_range()].to_string());
+            }
+            current = parent;
+        }
+        None
+    }
+
+    fn reference_kinds(&self) -> &'static [&'static str] {
+        &["identifier", "type_identifier"]
+    }
+}
+
 pub fn language() -> Language {
     tree_sitter_rust::LANGUAGE.into()
 }
@@ -53,6 +111,12 @@ pub fn method_query(type_name: &str, method_name: &str) -> String {
     )
 }
 
+/// Node kinds that count as leading docs/attributes for `--with-docs`:
+/// `///`/`//!`/block comments and `#[derive(...)]`-style attributes.
+pub fn is_doc_node(kind: &str) -> bool {
+    matches!(kind, "line_comment" | "block_comment" | "attribute_item")
+}
+
 pub fn list_query() -> &'static str {
     r#"
     (function_item name: (identifier) @func_name) @function
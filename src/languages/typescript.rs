@@ -1,5 +1,71 @@
 use tree_sitter::Language;
 
+use super::LanguageSupport;
+
+/// TypeScript and TSX share every query (TSX's grammar is a superset), so
+/// one impl serves both extensions; only `language()` differs.
+pub struct TypeScript {
+    tsx: bool,
+}
+
+pub fn make_ts() -> Box<dyn LanguageSupport> {
+    Box::new(TypeScript { tsx: false })
+}
+
+pub fn make_tsx() -> Box<dyn LanguageSupport> {
+    Box::new(TypeScript { tsx: true })
+}
+
+impl LanguageSupport for TypeScript {
+    fn language(&self) -> Language {
+        if self.tsx {
+            language_tsx()
+        } else {
+            language_typescript()
+        }
+    }
+
+    fn function_query(&self, name: &str) -> String {
+        function_query(name)
+    }
+
+    fn class_query(&self, name: &str) -> String {
+        class_query(name)
+    }
+
+    fn method_query(&self, class_name: &str, method_name: &str) -> String {
+        method_query(class_name, method_name)
+    }
+
+    fn interface_query(&self, name: &str) -> Option<String> {
+        Some(interface_query(name))
+    }
+
+    fn list_query(&self) -> &'static str {
+        list_query()
+    }
+
+    fn is_doc_node(&self, kind: &str) -> bool {
+        is_doc_node(kind)
+    }
+
+    fn qualifier_for(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        let mut current = node;
+        while let Some(parent) = current.parent() {
+            if parent.kind() == "class_declaration" {
+                let name = parent.child_by_field_name("name")?;
+                return Some(source[name.byte_range()].to_string());
+            }
+            current = parent;
+        }
+        None
+    }
+
+    fn reference_kinds(&self) -> &'static [&'static str] {
+        &["identifier", "type_identifier"]
+    }
+}
+
 pub fn language_typescript() -> Language {
     tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()
 }
@@ -83,6 +149,12 @@ pub fn method_query(class_name: &str, method_name: &str) -> String {
     )
 }
 
+/// Node kinds that count as leading docs/attributes for `--with-docs`:
+/// line/block comments, which is also where JSDoc blocks live.
+pub fn is_doc_node(kind: &str) -> bool {
+    matches!(kind, "comment")
+}
+
 pub fn list_query() -> &'static str {
     r#"
     (function_declaration name: (identifier) @func_name) @function
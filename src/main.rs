@@ -1,4 +1,5 @@
 mod extract;
+mod index;
 mod languages;
 
 use std::fs;
@@ -6,20 +7,42 @@ use std::path::Path;
 use std::process::ExitCode;
 
 fn main() -> ExitCode {
-    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
 
     if args.is_empty() {
-        eprintln!("Usage: sread <file>:<symbol>");
-        eprintln!("       sread <file>:<type>:<name>");
-        eprintln!("       sread <file> --list");
+        eprintln!("Usage: sread <file>:<symbol> [--with-docs] [--json] [--expand]");
+        eprintln!("       sread <file>:<type>:<name> [--with-docs] [--json] [--expand]");
+        eprintln!("       sread <file>@<line>");
+        eprintln!("       sread <file|dir> --list [--json]");
+        eprintln!("       sread <dir> --find <name> [--fuzzy] [--json]");
         return ExitCode::from(2);
     }
 
+    let with_docs = take_flag(&mut args, "--with-docs");
+    let json = take_flag(&mut args, "--json");
+    let fuzzy = take_flag(&mut args, "--fuzzy");
+    let has_expand = take_flag(&mut args, "--expand");
+    let has_closure = take_flag(&mut args, "--closure");
+    let expand = has_expand || has_closure;
+
     if args.len() == 2 && args[1] == "--list" {
-        return list_symbols(&args[0]);
+        let path = Path::new(&args[0]);
+        if path.is_dir() {
+            return project_list(path, json);
+        }
+        return list_symbols(&args[0], json);
+    }
+
+    if args.len() == 3 && args[1] == "--find" {
+        return project_find(Path::new(&args[0]), &args[2], fuzzy, json);
     }
-    
+
     let input = &args[0];
+
+    if let Some((file_path, line)) = parse_at_line(input) {
+        return symbol_at_line_and_print(&file_path, line);
+    }
+
     let (file_path, symbol) = match parse_input(input) {
         Some(v) => v,
         None => {
@@ -28,17 +51,27 @@ fn main() -> ExitCode {
         }
     };
 
-    extract_and_print(&file_path, &symbol)
+    extract_and_print(&file_path, &symbol, with_docs, json, expand)
+}
+
+/// Removes the first occurrence of `flag` from `args` in place, returning
+/// whether it was present.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    if let Some(pos) = args.iter().position(|a| a == flag) {
+        args.remove(pos);
+        true
+    } else {
+        false
+    }
 }
 
 fn parse_input(input: &str) -> Option<(String, String)> {
     // find the last colon that separates file from symbol
     // handle Windows paths (C:\...) by looking for pattern after extension
-    let extensions = [".py:", ".ts:", ".tsx:", ".js:", ".jsx:", ".mts:", ".cts:", ".mjs:", ".cjs:"];
-
-    for ext in extensions {
-        if let Some(pos) = input.find(ext) {
-            let split_pos = pos + ext.len() - 1; // position of the colon
+    for ext in languages::supported_extensions() {
+        let pattern = format!(".{ext}:");
+        if let Some(pos) = input.find(&pattern) {
+            let split_pos = pos + pattern.len() - 1; // position of the colon
             let file = &input[..split_pos];
             let symbol = &input[split_pos + 1..];
             if !symbol.is_empty() {
@@ -50,7 +83,38 @@ fn parse_input(input: &str) -> Option<(String, String)> {
     None
 }
 
-fn extract_and_print(file_path: &str, symbol: &str) -> ExitCode {
+/// Parses the `<file>@<line>` reverse-lookup syntax.
+fn parse_at_line(input: &str) -> Option<(String, usize)> {
+    let (file, line_str) = input.rsplit_once('@')?;
+    let line: usize = line_str.parse().ok()?;
+    Some((file.to_string(), line))
+}
+
+fn symbol_at_line_and_print(file_path: &str, line: usize) -> ExitCode {
+    let path = Path::new(file_path);
+
+    let source = match fs::read_to_string(path) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("Error reading file: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match extract::symbol_at_line(&source, path, line) {
+        Ok((name, code)) => {
+            println!("{name}:");
+            print!("{code}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::from(1)
+        }
+    }
+}
+
+fn extract_and_print(file_path: &str, symbol: &str, with_docs: bool, json: bool, expand: bool) -> ExitCode {
     let path = Path::new(file_path);
 
     let source = match fs::read_to_string(path) {
@@ -73,9 +137,56 @@ fn extract_and_print(file_path: &str, symbol: &str) -> ExitCode {
         symbol.to_string()
     };
 
-    match extract::extract_symbol(&source, path, &symbol) {
-        Ok(code) => {
-            print!("{code}");
+    if expand {
+        return match extract::extract_closure(&source, path, &symbol, with_docs) {
+            Ok(pieces) => {
+                if json {
+                    let entries: Vec<String> = pieces
+                        .iter()
+                        .map(|(name, extracted)| {
+                            format!(
+                                "{{\"name\":{},\"code\":{},\"start_line\":{},\"end_line\":{},\"start_byte\":{},\"end_byte\":{}}}",
+                                json_string(name),
+                                json_string(&extracted.code),
+                                extracted.start_line,
+                                extracted.end_line,
+                                extracted.start_byte,
+                                extracted.end_byte,
+                            )
+                        })
+                        .collect();
+                    println!("[{}]", entries.join(","));
+                } else {
+                    let code = pieces
+                        .iter()
+                        .map(|(_, extracted)| extracted.code.as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n\n");
+                    print!("{code}");
+                }
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::from(1)
+            }
+        };
+    }
+
+    match extract::extract_symbol(&source, path, &symbol, with_docs) {
+        Ok(extracted) => {
+            if json {
+                println!(
+                    "{{\"code\":{},\"start_line\":{},\"end_line\":{},\"start_byte\":{},\"end_byte\":{}}}",
+                    json_string(&extracted.code),
+                    extracted.start_line,
+                    extracted.end_line,
+                    extracted.start_byte,
+                    extracted.end_byte,
+                );
+            } else {
+                print!("{}", extracted.code);
+            }
             ExitCode::SUCCESS
         }
         Err(e) => {
@@ -85,7 +196,7 @@ fn extract_and_print(file_path: &str, symbol: &str) -> ExitCode {
     }
 }
 
-fn list_symbols(file_path: &str) -> ExitCode {
+fn list_symbols(file_path: &str, json: bool) -> ExitCode {
     let path = Path::new(file_path);
 
     let source = match fs::read_to_string(path) {
@@ -98,8 +209,26 @@ fn list_symbols(file_path: &str) -> ExitCode {
 
     match extract::list_symbols(&source, path) {
         Ok(symbols) => {
-            for sym in symbols {
-                println!("{}: {}", sym.kind, sym.name);
+            if json {
+                let entries: Vec<String> = symbols
+                    .iter()
+                    .map(|sym| {
+                        format!(
+                            "{{\"kind\":{},\"name\":{},\"start_line\":{},\"end_line\":{},\"start_byte\":{},\"end_byte\":{}}}",
+                            json_string(&sym.kind),
+                            json_string(&sym.name),
+                            sym.start_line,
+                            sym.end_line,
+                            sym.start_byte,
+                            sym.end_byte,
+                        )
+                    })
+                    .collect();
+                println!("[{}]", entries.join(","));
+            } else {
+                for sym in symbols {
+                    println!("{}: {}", sym.kind, sym.name);
+                }
             }
             ExitCode::SUCCESS
         }
@@ -109,3 +238,96 @@ fn list_symbols(file_path: &str) -> ExitCode {
         }
     }
 }
+
+fn project_list(dir: &Path, json: bool) -> ExitCode {
+    let files = index::index_project(dir);
+
+    if json {
+        let entries: Vec<String> = files
+            .iter()
+            .map(|file| {
+                let symbols: Vec<String> = file
+                    .symbols
+                    .iter()
+                    .map(|sym| {
+                        format!(
+                            "{{\"kind\":{},\"name\":{},\"start_line\":{},\"end_line\":{},\"start_byte\":{},\"end_byte\":{}}}",
+                            json_string(&sym.kind),
+                            json_string(&sym.name),
+                            sym.start_line,
+                            sym.end_line,
+                            sym.start_byte,
+                            sym.end_byte,
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{{\"file\":{},\"symbols\":[{}]}}",
+                    json_string(&file.path.to_string_lossy()),
+                    symbols.join(","),
+                )
+            })
+            .collect();
+        println!("[{}]", entries.join(","));
+    } else {
+        for file in files {
+            println!("{}:", file.path.display());
+            for sym in file.symbols {
+                println!("  {}: {}", sym.kind, sym.name);
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn project_find(dir: &Path, name: &str, fuzzy: bool, json: bool) -> ExitCode {
+    let matches = index::find_symbol(dir, name, fuzzy);
+
+    if json {
+        let entries: Vec<String> = matches
+            .iter()
+            .map(|m| {
+                format!(
+                    "{{\"file\":{},\"name\":{},\"kind\":{},\"line\":{},\"distance\":{}}}",
+                    json_string(&m.path.to_string_lossy()),
+                    json_string(&m.name),
+                    json_string(&m.kind),
+                    m.start_line,
+                    m.distance,
+                )
+            })
+            .collect();
+        println!("[{}]", entries.join(","));
+    } else {
+        for m in &matches {
+            println!("{}:{}: {}: {}", m.path.display(), m.start_line, m.kind, m.name);
+        }
+    }
+
+    if matches.is_empty() {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Minimal JSON string encoding (quotes, backslashes, control characters) —
+/// enough for the symbol names and source snippets `--json` emits.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}